@@ -0,0 +1,78 @@
+use serde::{Serialize, Serializer};
+
+/// Kinds of assets the frontend may request, used to make "not found" errors
+/// discriminable without parsing a message string.
+#[derive(Debug, Clone)]
+pub enum AssetKind {
+    Model,
+    Audio,
+    /// Any other kind, named by its label: every manifest-declared target
+    /// (e.g. `"texture"`, and also `"model"`/`"audio"` when requested through
+    /// `load_asset`), the manifest itself, or an unknown `game-asset://`
+    /// category.
+    Other(String),
+}
+
+impl AssetKind {
+    fn as_str(&self) -> &str {
+        match self {
+            AssetKind::Model => "model",
+            AssetKind::Audio => "audio",
+            AssetKind::Other(target) => target,
+        }
+    }
+}
+
+impl std::fmt::Display for AssetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Errors surfaced by Tauri commands, kept structured so the webview can
+/// branch on `kind` instead of pattern-matching a formatted message.
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("{kind} asset {name:?} not found in resources")]
+    AssetNotFound { kind: AssetKind, name: String },
+
+    #[error("no entry matching *.{ext} found in zip {zip:?}")]
+    NoMatchingEntry { zip: String, ext: String },
+
+    #[error("no manifest entry for asset id {asset_id:?}")]
+    AssetIdNotFound { asset_id: String },
+}
+
+impl Serialize for CommandError {
+    /// Serializes as `{ "kind": ..., "message": ... }` so the frontend can
+    /// discriminate error kinds instead of string-matching.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let kind = match self {
+            CommandError::Io(_) => "io",
+            CommandError::Zip(_) => "zip",
+            CommandError::Json(_) => "json",
+            CommandError::AssetNotFound { .. } => "asset_not_found",
+            CommandError::NoMatchingEntry { .. } => "no_matching_entry",
+            CommandError::AssetIdNotFound { .. } => "asset_id_not_found",
+        };
+
+        let mut state = serializer.serialize_struct("CommandError", 2)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
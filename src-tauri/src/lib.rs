@@ -1,125 +1,234 @@
+mod error;
+mod manifest;
+mod model;
+mod protocol;
+
 use tauri::Manager;
+#[cfg(not(target_os = "android"))]
 use tauri::path::BaseDirectory;
+#[cfg(not(target_os = "android"))]
 use std::fs;
+#[cfg(not(target_os = "android"))]
+use std::path::PathBuf;
 
-#[tauri::command]
-fn load_model_fbx_from_zip(app: tauri::AppHandle, zip_filename: String) -> Result<Vec<u8>, String> {
-    use std::io::Read;
-
-    fn read_zip_entry(zip_path: &std::path::Path) -> Result<Vec<u8>, String> {
-        let file = fs::File::open(zip_path)
-            .map_err(|e| format!("Failed to open zip file {:?}: {}", zip_path, e))?;
-        let mut archive = zip::ZipArchive::new(file)
-            .map_err(|e| format!("Failed to read zip archive {:?}: {}", zip_path, e))?;
-
-        // Pick the first .fbx entry (keeps API simple; supports current assets).
-        for i in 0..archive.len() {
-            let mut entry = archive
-                .by_index(i)
-                .map_err(|e| format!("Failed to read zip entry {} in {:?}: {}", i, zip_path, e))?;
-            let name = entry.name().to_string();
-            if !name.to_lowercase().ends_with(".fbx") {
-                continue;
-            }
-            let mut buf = Vec::with_capacity(entry.size() as usize);
-            entry
-                .read_to_end(&mut buf)
-                .map_err(|e| format!("Failed to read FBX entry {} in {:?}: {}", name, zip_path, e))?;
-            return Ok(buf);
-        }
+use error::{AssetKind, CommandError};
+use manifest::AssetManifest;
+use model::ModelBundle;
 
-        Err(format!(
-            "No .fbx entry found in zip {:?}",
-            zip_path
-        ))
+/// Path of `filename` under `dev_subdir`, relative to the `resources/`
+/// directory (e.g. `"resources/models/rifle.zip"`, or `"resources/assets.json"`
+/// when `dev_subdir` is empty). This is the one place that encodes how a
+/// (subdir, filename) pair maps to a resource path, so the dev-mode lookup
+/// below and the Android asset lookup in [`read_android_resource`] can't
+/// drift apart on where `resources/` lives.
+fn resource_relative_path(dev_subdir: &str, filename: &str) -> String {
+    if dev_subdir.is_empty() {
+        format!("resources/{}", filename)
+    } else {
+        format!("resources/{}/{}", dev_subdir, filename)
     }
+}
 
-    // 1) Dev Mode: read directly from repo folder.
-    #[cfg(debug_assertions)]
+/// Resolves `filename` under `dev_subdir` on disk: the dev-mode project
+/// folder first (debug desktop builds only) and then the bundled resource
+/// directory, trying a handful of layout conventions. Not meaningful on
+/// Android, where bundled resources live inside the APK asset manager
+/// instead of the filesystem — see [`read_android_resource`].
+#[cfg(not(target_os = "android"))]
+fn resolve_existing_resource(app: &tauri::AppHandle, dev_subdir: &str, filename: &str) -> Option<PathBuf> {
+    #[cfg(all(debug_assertions, not(target_os = "ios")))]
     {
-        use std::path::PathBuf;
-        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| e.to_string())?;
-        let mut dev_path = PathBuf::from(manifest_dir);
-        dev_path.push("resources/models");
-        dev_path.push(&zip_filename);
-        if dev_path.exists() {
-            return read_zip_entry(&dev_path);
+        if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+            let dev_path = PathBuf::from(manifest_dir).join(resource_relative_path(dev_subdir, filename));
+            log::debug!("trying dev resource candidate {:?}", dev_path);
+            if dev_path.exists() {
+                return Some(dev_path);
+            }
         }
     }
 
-    // 2) Production: resolve from bundled resources.
-    let maybe_paths = vec![
-        format!("resources/models/{}", zip_filename),
-        format!("resources/{}", zip_filename),
-        format!("models/{}", zip_filename),
-        zip_filename.clone(),
-    ];
+    let maybe_paths = if dev_subdir.is_empty() {
+        vec![resource_relative_path(dev_subdir, filename), filename.to_string()]
+    } else {
+        vec![
+            resource_relative_path(dev_subdir, filename),
+            format!("resources/{}", filename),
+            format!("{}/{}", dev_subdir, filename),
+            filename.to_string(),
+        ]
+    };
 
     for path_str in maybe_paths {
-        if let Ok(path) = app.path().resolve(&path_str, BaseDirectory::Resource) {
-            if path.exists() {
-                return read_zip_entry(&path);
+        match app.path().resolve(&path_str, BaseDirectory::Resource) {
+            Ok(path) => {
+                log::debug!("trying bundled resource candidate {:?}", path);
+                if path.exists() {
+                    return Some(path);
+                }
             }
+            Err(e) => log::debug!("could not resolve resource candidate {}: {}", path_str, e),
         }
     }
 
-    Err(format!(
-        "Could not find model zip {} in resources",
-        zip_filename
-    ))
+    None
 }
 
-// Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
-#[tauri::command]
-fn greet(name: &str) -> String {
-    format!("Hello, {}! You've been greeted from Rust!", name)
+/// Reads `filename` under `dev_subdir` via the Tauri asset resolver, which
+/// works against Android's APK-backed asset manager where paths aren't
+/// directly readable with `std::fs`.
+#[cfg(target_os = "android")]
+fn read_android_resource(app: &tauri::AppHandle, dev_subdir: &str, filename: &str, kind: AssetKind) -> Result<Vec<u8>, CommandError> {
+    let rel_path = resource_relative_path(dev_subdir, filename);
+
+    log::debug!("trying android asset candidate {}", rel_path);
+    app.asset_resolver().get(rel_path.clone()).map(|asset| asset.bytes).ok_or_else(|| {
+        log::warn!("android asset not found: {}", rel_path);
+        CommandError::AssetNotFound {
+            kind,
+            name: filename.to_string(),
+        }
+    })
 }
 
-#[tauri::command]
-fn load_audio_asset(app: tauri::AppHandle, filename: String) -> Result<Vec<u8>, String> {
-    // 1. Dev Mode Fallback: Check directly in the project folder
-    #[cfg(debug_assertions)]
+/// Locates and reads `filename` under `dev_subdir`, dispatching to the
+/// right platform mechanism. Shared by the `load_*` commands and the
+/// `game-asset://` protocol handler so both stay in sync on where assets
+/// live. `kind` tags a resulting "not found" error with the caller's
+/// semantic asset kind (e.g. `AssetKind::Model`), not the subdir string.
+pub(crate) fn read_resource_bytes(app: &tauri::AppHandle, dev_subdir: &str, filename: &str, kind: AssetKind) -> Result<Vec<u8>, CommandError> {
+    #[cfg(target_os = "android")]
+    {
+        read_android_resource(app, dev_subdir, filename, kind)
+    }
+
+    #[cfg(not(target_os = "android"))]
     {
-        use std::path::PathBuf;
-
-        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| e.to_string())?;
-        let mut dev_path = PathBuf::from(manifest_dir);
-        // Adjusted for new structure: src-tauri/resources/audio
-        dev_path.push("resources/audio");
-        dev_path.push(&filename);
-        
-        if dev_path.exists() {
-             return fs::read(&dev_path).map_err(|e| format!("Failed to read file from dev path {:?}: {}", dev_path, e));
+        let path = resolve_existing_resource(app, dev_subdir, filename).ok_or_else(|| {
+            log::warn!("could not find resource {:?}/{} in any candidate path", dev_subdir, filename);
+            CommandError::AssetNotFound {
+                kind,
+                name: filename.to_string(),
+            }
+        })?;
+        fs::read(&path).map_err(|e| {
+            log::error!("failed to read resolved resource {:?}: {}", path, e);
+            CommandError::from(e)
+        })
+    }
+}
+
+/// Reads the first zip entry whose name ends with `.{ext}` (case
+/// insensitive). Kept simple to support today's single-mesh archives.
+/// `zip_label` identifies the archive in log output (its resource filename,
+/// since the bytes no longer carry a path once read into memory).
+pub(crate) fn read_first_zip_entry_by_ext(zip_bytes: Vec<u8>, ext: &str, zip_label: &str) -> Result<Vec<u8>, CommandError> {
+    use std::io::{Cursor, Read};
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(zip_bytes))?;
+
+    let suffix = format!(".{}", ext.to_lowercase());
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+        if !name.to_lowercase().ends_with(&suffix) {
+            continue;
         }
+        let mut buf = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut buf).map_err(|e| {
+            log::error!("failed to read entry {} from zip {}: {}", name, zip_label, e);
+            CommandError::from(e)
+        })?;
+        return Ok(buf);
     }
 
-    // 2. Production / Standard Resource Mode
-    let maybe_paths = vec![
-        format!("resources/audio/{}", filename), 
-        format!("resources/{}", filename),       
-        format!("audio/{}", filename),           
-        filename.clone(), 
-    ];
+    log::warn!("no *.{} entry found in zip {}", ext, zip_label);
+    Err(CommandError::NoMatchingEntry {
+        zip: zip_label.to_string(),
+        ext: ext.to_string(),
+    })
+}
 
-    for path_str in maybe_paths {
-         match app.path().resolve(&path_str, BaseDirectory::Resource) {
-            Ok(path) => {
-                if path.exists() {
-                     return fs::read(&path).map_err(|e| format!("Failed to read file at {:?}: {}", path, e));
-                }
-            },
-            Err(_) => {},
-         }
+/// Reads the exact named entry from a zip archive, for callers (like
+/// [`load_asset`]) that already know which entry they want instead of
+/// guessing by extension.
+fn read_named_zip_entry(zip_bytes: Vec<u8>, entry_name: &str, zip_label: &str) -> Result<Vec<u8>, CommandError> {
+    use std::io::{Cursor, Read};
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(zip_bytes))?;
+    let mut entry = archive.by_name(entry_name).map_err(|e| {
+        log::warn!("entry {} not found in zip {}: {}", entry_name, zip_label, e);
+        CommandError::from(e)
+    })?;
+    let mut buf = Vec::with_capacity(entry.size() as usize);
+    entry.read_to_end(&mut buf).map_err(|e| {
+        log::error!("failed to read entry {} from zip {}: {}", entry_name, zip_label, e);
+        CommandError::from(e)
+    })?;
+    Ok(buf)
+}
+
+#[tauri::command]
+fn load_model_fbx_from_zip(app: tauri::AppHandle, zip_filename: String) -> Result<Vec<u8>, CommandError> {
+    let bytes = read_resource_bytes(&app, "models", &zip_filename, AssetKind::Model)?;
+    read_first_zip_entry_by_ext(bytes, "fbx", &zip_filename)
+}
+
+/// Extracts a model mesh plus every texture bundled alongside it in the
+/// same zip, so the renderer can resolve texture references in-memory
+/// from a single IPC call.
+#[tauri::command]
+fn load_model_bundle_from_zip(app: tauri::AppHandle, zip_filename: String) -> Result<ModelBundle, CommandError> {
+    let bytes = read_resource_bytes(&app, "models", &zip_filename, AssetKind::Model)?;
+    model::read_model_bundle(bytes, &zip_filename)
+}
+
+// Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+#[tauri::command]
+fn greet(name: &str) -> Result<String, CommandError> {
+    Ok(format!("Hello, {}! You've been greeted from Rust!", name))
+}
+
+#[tauri::command]
+fn load_audio_asset(app: tauri::AppHandle, filename: String) -> Result<Vec<u8>, CommandError> {
+    read_resource_bytes(&app, "audio", &filename, AssetKind::Audio)
+}
+
+/// Resolves a logical asset ID through `resources/assets.json`, selecting
+/// the exact named zip entry (or reading the source file directly) rather
+/// than guessing by extension.
+#[tauri::command]
+fn load_asset(app: tauri::AppHandle, asset_id: String) -> Result<Vec<u8>, CommandError> {
+    let manifest = app.state::<AssetManifest>();
+    let descriptor = manifest
+        .get(&asset_id)
+        .ok_or_else(|| CommandError::AssetIdNotFound { asset_id: asset_id.clone() })?
+        .clone();
+
+    let bytes = read_resource_bytes(&app, descriptor.subdir(), &descriptor.source, AssetKind::Other(descriptor.target.clone()))?;
+
+    match &descriptor.entry {
+        Some(entry_name) => read_named_zip_entry(bytes, entry_name, &descriptor.source),
+        None => Ok(bytes),
     }
-    
-    Err(format!("Could not find audio file {} in resources", filename))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_log::Builder::default().build())
         .plugin(tauri_plugin_opener::init())
-    .invoke_handler(tauri::generate_handler![greet, load_audio_asset, load_model_fbx_from_zip])
+        .register_uri_scheme_protocol("game-asset", protocol::handle_game_asset_request)
+        .setup(|app| {
+            app.manage(AssetManifest::load(&app.handle())?);
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            load_audio_asset,
+            load_model_fbx_from_zip,
+            load_model_bundle_from_zip,
+            load_asset
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::error::{AssetKind, CommandError};
+
+/// Where a logical asset lives and how to pull it out of that location.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct AssetDescriptor {
+    /// Path to the backing file, relative to the resource subdirectory
+    /// (e.g. `"character.zip"` under `resources/models`).
+    pub source: String,
+    /// Exact entry name to extract when `source` is a zip archive. Absent
+    /// when `source` is itself the asset (e.g. a plain audio file).
+    #[serde(default)]
+    pub entry: Option<String>,
+    /// Logical asset kind (`"model"`, `"audio"`, `"texture"`, ...), used to
+    /// tag errors and, absent `source_dir`, to guess the subdirectory.
+    pub target: String,
+    /// Resource subdirectory `source` lives under, when it differs from
+    /// `target`'s default — e.g. a texture bundled inside a model's zip,
+    /// which lives under `resources/models` alongside it.
+    #[serde(default)]
+    pub source_dir: Option<String>,
+}
+
+impl AssetDescriptor {
+    /// Resource subdirectory to resolve `source` under.
+    pub fn subdir(&self) -> &str {
+        self.source_dir.as_deref().unwrap_or_else(|| subdir_for_target(&self.target))
+    }
+}
+
+/// Maps logical asset IDs to where they live, loaded once at startup from
+/// `resources/assets.json` so adding a new asset is a data change instead
+/// of a code change.
+pub(crate) struct AssetManifest(HashMap<String, AssetDescriptor>);
+
+impl AssetManifest {
+    pub fn load(app: &tauri::AppHandle) -> Result<Self, CommandError> {
+        let bytes = crate::read_resource_bytes(app, "", "assets.json", AssetKind::Other("manifest".to_string()))?;
+        let entries: HashMap<String, AssetDescriptor> = serde_json::from_slice(&bytes)?;
+        Ok(AssetManifest(entries))
+    }
+
+    pub fn get(&self, asset_id: &str) -> Option<&AssetDescriptor> {
+        self.0.get(asset_id)
+    }
+}
+
+/// Default resource subdirectory for a logical asset `target`, used when a
+/// descriptor doesn't set `source_dir` explicitly.
+fn subdir_for_target(target: &str) -> &str {
+    match target {
+        "model" => "models",
+        other => other,
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::error::CommandError;
+
+const MODEL_EXTENSIONS: &[&str] = &["fbx", "glb", "gltf", "obj"];
+const TEXTURE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "tga", "dds"];
+
+/// A model mesh plus every texture bundled alongside it in the same zip, so
+/// the frontend can resolve texture references in-memory from a single IPC
+/// call instead of round-tripping per texture.
+#[derive(Debug, Serialize)]
+pub(crate) struct ModelBundle {
+    /// Model format (`fbx`/`glb`/`gltf`/`obj`) so the frontend loader can
+    /// dispatch to the right parser.
+    pub format: String,
+    pub model: Vec<u8>,
+    /// Texture bytes keyed by their path inside the zip.
+    pub textures: HashMap<String, Vec<u8>>,
+}
+
+/// Reads the first recognized model entry and every texture entry out of
+/// `zip_bytes` in one pass. `zip_label` identifies the archive in log
+/// output (its resource filename).
+pub(crate) fn read_model_bundle(zip_bytes: Vec<u8>, zip_label: &str) -> Result<ModelBundle, CommandError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(zip_bytes))?;
+
+    let mut model: Option<(String, Vec<u8>)> = None;
+    let mut textures = HashMap::new();
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+        let ext = Path::new(&name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        if model.is_none() && MODEL_EXTENSIONS.contains(&ext.as_str()) {
+            log::debug!("using {} as model entry in zip {}", name, zip_label);
+            let mut buf = Vec::with_capacity(entry.size() as usize);
+            entry.read_to_end(&mut buf).map_err(|e| {
+                log::error!("failed to read model entry {} from zip {}: {}", name, zip_label, e);
+                CommandError::from(e)
+            })?;
+            model = Some((ext, buf));
+            continue;
+        }
+
+        if TEXTURE_EXTENSIONS.contains(&ext.as_str()) {
+            log::debug!("bundling texture entry {} from zip {}", name, zip_label);
+            let mut buf = Vec::with_capacity(entry.size() as usize);
+            entry.read_to_end(&mut buf).map_err(|e| {
+                log::error!("failed to read texture entry {} from zip {}: {}", name, zip_label, e);
+                CommandError::from(e)
+            })?;
+            textures.insert(name, buf);
+        }
+    }
+
+    let (format, model) = model.ok_or_else(|| {
+        log::warn!("no model entry (fbx/glb/gltf/obj) found in zip {}", zip_label);
+        CommandError::NoMatchingEntry {
+            zip: zip_label.to_string(),
+            ext: "fbx/glb/gltf/obj".to_string(),
+        }
+    })?;
+
+    Ok(ModelBundle { format, model, textures })
+}